@@ -1,10 +1,13 @@
 use std::{
-    hash::{BuildHasher, Hash, RandomState},
+    collections::hash_map::DefaultHasher,
+    hash::{BuildHasher, Hash, Hasher, RandomState},
     marker::PhantomData,
 };
 
+use serde::{Deserialize, Serialize};
+
 /// Fixed-size set of booleans represented by a vector of bytes.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct BitSet {
     bits: Vec<u8>,
     // len: usize,
@@ -58,15 +61,80 @@ impl BitSet {
             self.bits[byte_idx] ^= 1 << bit_idx
         }
     }
+
+    /// Bitwise-ORs `self` and `other` byte-for-byte. Assumes both bitsets
+    /// were built with the same length, which callers must check themselves.
+    fn union(&self, other: &Self) -> Self {
+        BitSet {
+            bits: self
+                .bits
+                .iter()
+                .zip(&other.bits)
+                .map(|(a, b)| a | b)
+                .collect(),
+        }
+    }
+
+    /// Bitwise-ANDs `self` and `other` byte-for-byte. Assumes both bitsets
+    /// were built with the same length, which callers must check themselves.
+    fn intersect(&self, other: &Self) -> Self {
+        BitSet {
+            bits: self
+                .bits
+                .iter()
+                .zip(&other.bits)
+                .map(|(a, b)| a & b)
+                .collect(),
+        }
+    }
+
+    /// The number of set bits, used to estimate cardinality.
+    fn count_ones(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum()
+    }
+}
+
+/// A seedable stand-in for [`RandomState`], whose seeds are chosen randomly
+/// per process and can't be recovered afterwards. Storing an explicit `u64`
+/// seed instead means a filter's hashers, and therefore the bit positions it
+/// queries, can be reconstructed exactly after being serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct SeededHasher(u64);
+
+impl SeededHasher {
+    /// Seeds a hasher randomly, for use when a filter isn't being restored
+    /// from previously serialized state.
+    fn random() -> Self {
+        SeededHasher(RandomState::new().hash_one(0u64))
+    }
+
+    fn seed(&self) -> u64 {
+        self.0
+    }
+}
+
+impl BuildHasher for SeededHasher {
+    type Hasher = DefaultHasher;
+
+    fn build_hasher(&self) -> DefaultHasher {
+        let mut hasher = DefaultHasher::new();
+        hasher.write_u64(self.0);
+        hasher
+    }
 }
 
 /// A bloom filter using an array of size `F` and `H` distinct hash functions.
 /// The constant parameters of this struct should be tuned for the filter's
 /// specific use case; under the defaults, the false positive rate should be <<
 /// 1% after 10K items are inserted.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
 pub struct BloomFilter<T: Hash, const F: usize = 150000, const H: usize = 5> {
-    hashers: Vec<RandomState>,
+    h1: SeededHasher,
+    h2: SeededHasher,
     filter: BitSet,
     _element_type: PhantomData<T>,
 }
@@ -74,33 +142,379 @@ pub struct BloomFilter<T: Hash, const F: usize = 150000, const H: usize = 5> {
 impl<T: Hash, const F: usize, const H: usize> BloomFilter<T, F, H> {
     pub fn new() -> Self {
         BloomFilter {
-            hashers: (0..H).map(|_| RandomState::new()).collect(),
+            h1: SeededHasher::random(),
+            h2: SeededHasher::random(),
             filter: BitSet::new(F),
             _element_type: PhantomData,
         }
     }
 
     pub fn insert(&mut self, item: &T) {
-        self.hashers
-            .iter()
-            .map(|rs| rs.hash_one(item))
-            .for_each(|hash| self.filter.set(hash as usize % F, true))
+        hash_positions(F, H, self.h1.hash_one(item), self.h2.hash_one(item))
+            .for_each(|pos| self.filter.set(pos, true))
     }
 
     pub fn contains(&self, item: &T) -> bool {
-        self.hashers
-            .iter()
-            .map(|rs| rs.hash_one(item))
-            .all(|hash| self.filter.get(hash as usize % F))
+        hash_positions(F, H, self.h1.hash_one(item), self.h2.hash_one(item))
+            .all(|pos| self.filter.get(pos))
+    }
+
+    /// Serializes this filter to a compact byte buffer: the `F`/`H`
+    /// parameters, the two hasher seeds, and the raw bitset bytes. Unlike the
+    /// generic [`serde`] support derived above, this format is specific to
+    /// `BloomFilter` and doesn't need a serializer in scope.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 + self.filter.bits.len());
+        bytes.extend_from_slice(&(F as u64).to_le_bytes());
+        bytes.extend_from_slice(&(H as u64).to_le_bytes());
+        bytes.extend_from_slice(&self.h1.seed().to_le_bytes());
+        bytes.extend_from_slice(&self.h2.seed().to_le_bytes());
+        bytes.extend_from_slice(&self.filter.bits);
+        bytes
+    }
+
+    /// Reconstructs a filter previously serialized with
+    /// [`to_bytes`][Self::to_bytes]. Returns `None` if `bytes` was produced
+    /// by a filter with different `F`/`H` parameters, or if its bitset part
+    /// isn't exactly the length that `F` requires.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 32 {
+            return None;
+        }
+
+        let stored_f = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+        let stored_h = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        if stored_f != F as u64 || stored_h != H as u64 {
+            return None;
+        }
+
+        let seed1 = u64::from_le_bytes(bytes[16..24].try_into().unwrap());
+        let seed2 = u64::from_le_bytes(bytes[24..32].try_into().unwrap());
+        let bits = bytes[32..].to_vec();
+
+        if bits.len() != BitSet::new(F).bits.len() {
+            return None;
+        }
+
+        Some(BloomFilter {
+            h1: SeededHasher(seed1),
+            h2: SeededHasher(seed2),
+            filter: BitSet { bits },
+            _element_type: PhantomData,
+        })
+    }
+
+    /// Combines `self` and `other` into a filter that answers `contains` for
+    /// the union of both filters' inserted items, by OR-ing their underlying
+    /// bits together. Only sound when both filters hash to the same
+    /// positions, so this fails with [`ConfigMismatch`] unless `other` was
+    /// built from the same hasher seeds as `self` (e.g. by one being cloned
+    /// from the other, or both restored from the same [`to_bytes`][Self::to_bytes] seeds).
+    pub fn union(&self, other: &Self) -> Result<Self, ConfigMismatch> {
+        if !self.has_same_hashers(other) {
+            return Err(ConfigMismatch);
+        }
+
+        Ok(BloomFilter {
+            h1: self.h1,
+            h2: self.h2,
+            filter: self.filter.union(&other.filter),
+            _element_type: PhantomData,
+        })
+    }
+
+    /// Combines `self` and `other` into a filter that answers `contains` for
+    /// the intersection of both filters' inserted items, by AND-ing their
+    /// underlying bits together. Subject to the same seed requirement as
+    /// [`union`][Self::union].
+    pub fn intersect(&self, other: &Self) -> Result<Self, ConfigMismatch> {
+        if !self.has_same_hashers(other) {
+            return Err(ConfigMismatch);
+        }
+
+        Ok(BloomFilter {
+            h1: self.h1,
+            h2: self.h2,
+            filter: self.filter.intersect(&other.filter),
+            _element_type: PhantomData,
+        })
+    }
+
+    fn has_same_hashers(&self, other: &Self) -> bool {
+        self.h1 == other.h1 && self.h2 == other.h2
+    }
+
+    /// Estimates the number of distinct items inserted so far from the
+    /// fraction of set bits `X / F`, by inverting the expected fill ratio
+    /// `X / F ≈ 1 - e^(-H * n / F)`: `n ≈ -(F / H) * ln(1 - X / F)`. Gives
+    /// callers a cardinality and saturation signal without storing elements.
+    pub fn count_estimate(&self) -> f64 {
+        let set_bits = self.filter.count_ones() as f64;
+        -(F as f64 / H as f64) * (1.0 - set_bits / F as f64).ln()
+    }
+}
+
+/// Error returned by [`BloomFilter::union`]/[`BloomFilter::intersect`] when
+/// the two filters weren't built from the same hasher seeds, and therefore
+/// would combine bits that don't correspond to the same hash positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigMismatch;
+
+impl std::fmt::Display for ConfigMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "filters must share the same hashing seeds to be combined"
+        )
     }
 }
 
+impl std::error::Error for ConfigMismatch {}
+
 impl<T: Hash, const F: usize, const H: usize> Default for BloomFilter<T, F, H> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Computes the `h` array positions that an item hashes to from just two
+/// hashes `h1`/`h2` of it, using the Kirsch–Mitzenmacher double-hashing
+/// scheme `g_i(x) = (h1 + i * h2) mod f`. This gives the same asymptotic
+/// false-positive behavior as `h` independent hashes while only requiring two
+/// calls to `hash_one` per operation. `f` and `h` are taken as plain
+/// parameters (rather than const generics) so the same routine backs
+/// [`BloomFilter`], [`CountingBloomFilter`], and the runtime-sized
+/// [`SizedBloomFilter`].
+fn hash_positions(f: usize, h: usize, h1: u64, h2: u64) -> impl Iterator<Item = usize> {
+    // Guard against the degenerate case where `h2 mod f == 0`, which would
+    // collapse every index down to `h1` and defeat the whole scheme. `h2 | 1`
+    // doesn't actually fix this: for an odd `f`, an odd multiple of `f` is
+    // still a multiple of `f`. Reduce into range first, then pin nonzero.
+    let mut h2 = h2 % f as u64;
+    if h2 == 0 {
+        h2 = 1;
+    }
+
+    (0..h).map(move |i| h1.wrapping_add((i as u64).wrapping_mul(h2)) as usize % f)
+}
+
+/// A saturating counter used by [`CountingBloomFilter`] to track how many
+/// times each of its slots has been incremented, which is what makes
+/// deletion possible. Once a counter saturates, it is pinned at its maximum
+/// value: decrementing past that point would make `remove` unsound, since
+/// the counter could no longer distinguish "one insert" from "many inserts".
+pub trait Counter: Copy {
+    /// The counter value returned by a brand new slot.
+    const ZERO: Self;
+    /// The counter's maximum representable value.
+    const MAX: Self;
+
+    /// Increments the counter, saturating at [`Counter::MAX`].
+    fn saturating_incr(self) -> Self;
+
+    /// Decrements the counter, saturating at [`Counter::ZERO`].
+    fn saturating_decr(self) -> Self;
+
+    fn is_zero(self) -> bool;
+
+    fn is_saturated(self) -> bool;
+}
+
+macro_rules! impl_counter {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl Counter for $t {
+                const ZERO: Self = 0;
+                const MAX: Self = <$t>::MAX;
+
+                fn saturating_incr(self) -> Self {
+                    self.saturating_add(1)
+                }
+
+                fn saturating_decr(self) -> Self {
+                    self.saturating_sub(1)
+                }
+
+                fn is_zero(self) -> bool {
+                    self == 0
+                }
+
+                fn is_saturated(self) -> bool {
+                    self == <$t>::MAX
+                }
+            }
+        )+
+    };
+}
+
+impl_counter!(u8, u16, u32, u64);
+
+/// A counting variant of [`BloomFilter`] that supports [`remove`][Self::remove]
+/// in addition to `insert`/`contains`, at the cost of trading each single bit
+/// for a small saturating counter (`u8` by default; use `C = u16` etc. for
+/// higher churn before saturation). `insert` increments the `H` counters an
+/// item hashes to, `remove` decrements them back down, and `contains` is true
+/// iff all `H` counters are nonzero.
+///
+/// Counters pin at their maximum value instead of wrapping, because a
+/// wraparound would let `remove` decrement a slot shared with another item
+/// down to zero and produce a false negative.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(serialize = "C: Serialize", deserialize = "C: Deserialize<'de>"))]
+pub struct CountingBloomFilter<
+    T: Hash,
+    C: Counter = u8,
+    const F: usize = 150000,
+    const H: usize = 5,
+> {
+    h1: SeededHasher,
+    h2: SeededHasher,
+    counters: Vec<C>,
+    _element_type: PhantomData<T>,
+}
+
+impl<T: Hash, C: Counter, const F: usize, const H: usize> CountingBloomFilter<T, C, F, H> {
+    pub fn new() -> Self {
+        CountingBloomFilter {
+            h1: SeededHasher::random(),
+            h2: SeededHasher::random(),
+            counters: vec![C::ZERO; F],
+            _element_type: PhantomData,
+        }
+    }
+
+    pub fn insert(&mut self, item: &T) {
+        for pos in hash_positions(F, H, self.h1.hash_one(item), self.h2.hash_one(item)) {
+            self.counters[pos] = self.counters[pos].saturating_incr();
+        }
+    }
+
+    /// Removes `item`, decrementing each of its `H` counters unless they are
+    /// already zero (nothing to remove) or saturated (pinned to avoid
+    /// corrupting counters shared with other items).
+    pub fn remove(&mut self, item: &T) {
+        for pos in hash_positions(F, H, self.h1.hash_one(item), self.h2.hash_one(item)) {
+            let counter = self.counters[pos];
+            if !counter.is_zero() && !counter.is_saturated() {
+                self.counters[pos] = counter.saturating_decr();
+            }
+        }
+    }
+
+    pub fn contains(&self, item: &T) -> bool {
+        hash_positions(F, H, self.h1.hash_one(item), self.h2.hash_one(item))
+            .all(|pos| !self.counters[pos].is_zero())
+    }
+}
+
+impl<T: Hash, C: Counter, const F: usize, const H: usize> Default
+    for CountingBloomFilter<T, C, F, H>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`BloomFilter`] whose array size and hash count are chosen at runtime
+/// from a target capacity and false-positive rate via
+/// [`with_params`][Self::with_params], instead of being tuned by hand through
+/// the `F`/`H` const generics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct SizedBloomFilter<T: Hash> {
+    h1: SeededHasher,
+    h2: SeededHasher,
+    filter: BitSet,
+    num_bits: usize,
+    num_hashes: usize,
+    _element_type: PhantomData<T>,
+}
+
+impl<T: Hash> SizedBloomFilter<T> {
+    /// Builds a filter sized for `expected_items` distinct insertions while
+    /// keeping the false-positive rate at or below `fp_rate`.
+    ///
+    /// The optimal array size `m` and hash count `k` are the standard bloom
+    /// filter formulas: `m = ceil(-(n * ln(p)) / (ln 2)^2)` and
+    /// `k = round((m / n) * ln 2)`, both clamped to at least 1 so the filter
+    /// never has a zero-sized array for `hash_positions` to divide by, or
+    /// zero hashes. `n` is `expected_items` clamped to at least 1, since `k`
+    /// divides by `n` and would otherwise blow up to `usize::MAX`.
+    ///
+    /// `fp_rate` must lie in the open interval `(0.0, 1.0)`, or this returns
+    /// [`InvalidFpRate`]: at `fp_rate == 0.0`, `ln(p)` is `-infinity` and `m`
+    /// saturates to `usize::MAX`, so the allocation below aborts the
+    /// process; at `fp_rate >= 1.0`, `m` degenerates to a 1-bit filter whose
+    /// `contains` is always true. Neither is a rate this constructor can
+    /// honor, so both ends are rejected rather than silently producing a
+    /// filter that doesn't do what was asked.
+    pub fn with_params(expected_items: usize, fp_rate: f64) -> Result<Self, InvalidFpRate> {
+        if !(fp_rate > 0.0 && fp_rate < 1.0) {
+            return Err(InvalidFpRate);
+        }
+
+        let n = usize::max(1, expected_items) as f64;
+        let ln2 = std::f64::consts::LN_2;
+
+        let num_bits = usize::max(1, (-(n * fp_rate.ln()) / ln2.powi(2)).ceil() as usize);
+        let num_hashes = usize::max(1, ((num_bits as f64 / n) * ln2).round() as usize);
+
+        Ok(SizedBloomFilter {
+            h1: SeededHasher::random(),
+            h2: SeededHasher::random(),
+            filter: BitSet::new(num_bits),
+            num_bits,
+            num_hashes,
+            _element_type: PhantomData,
+        })
+    }
+
+    /// The array size `m` derived from the constructor's target capacity and
+    /// false-positive rate.
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    /// The hash count `k` derived from the constructor's target capacity and
+    /// false-positive rate.
+    pub fn num_hashes(&self) -> usize {
+        self.num_hashes
+    }
+
+    pub fn insert(&mut self, item: &T) {
+        hash_positions(
+            self.num_bits,
+            self.num_hashes,
+            self.h1.hash_one(item),
+            self.h2.hash_one(item),
+        )
+        .for_each(|pos| self.filter.set(pos, true))
+    }
+
+    pub fn contains(&self, item: &T) -> bool {
+        hash_positions(
+            self.num_bits,
+            self.num_hashes,
+            self.h1.hash_one(item),
+            self.h2.hash_one(item),
+        )
+        .all(|pos| self.filter.get(pos))
+    }
+}
+
+/// Error returned by [`SizedBloomFilter::with_params`] when `fp_rate` isn't
+/// in the open interval `(0.0, 1.0)` the sizing formula requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidFpRate;
+
+impl std::fmt::Display for InvalidFpRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "fp_rate must be in the open interval (0.0, 1.0)")
+    }
+}
+
+impl std::error::Error for InvalidFpRate {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,6 +612,8 @@ mod tests {
 
     #[test]
     fn bloom_filter_false_positives() {
+        // Also exercises the double-hashing scheme in `hash_positions`, since
+        // it derives all `H` positions from just two underlying hashes.
         let mut false_pos = 0;
 
         let mut bf: BloomFilter<usize> = BloomFilter::new();
@@ -217,4 +633,281 @@ mod tests {
             FALSE_POS_UPPER_BOUND
         )
     }
+
+    #[test]
+    fn counting_bloom_filter_accepts_one() {
+        let num = 1234;
+
+        let mut cbf: CountingBloomFilter<i32> = CountingBloomFilter::new();
+        cbf.insert(&num);
+
+        assert!(
+            cbf.contains(&num),
+            "counting bloom filter doesn't contain inserted item"
+        )
+    }
+
+    #[test]
+    fn counting_bloom_filter_rejects_one() {
+        let num = 1234;
+
+        let cbf: CountingBloomFilter<i32> = CountingBloomFilter::new();
+
+        assert!(
+            !cbf.contains(&num),
+            "empty counting bloom filter claims to contain item"
+        )
+    }
+
+    #[test]
+    fn counting_bloom_filter_accepts_many() {
+        let mut cbf: CountingBloomFilter<usize> = CountingBloomFilter::new();
+
+        (0..BLOOM_FILTER_MAX).for_each(|i| cbf.insert(&i));
+
+        assert!(
+            (0..BLOOM_FILTER_MAX).all(|i| cbf.contains(&i)),
+            "counting bloom filter doesn't contain inserted items"
+        )
+    }
+
+    #[test]
+    fn counting_bloom_filter_remove() {
+        let num = 1234;
+
+        let mut cbf: CountingBloomFilter<i32> = CountingBloomFilter::new();
+        cbf.insert(&num);
+        cbf.remove(&num);
+
+        assert!(
+            !cbf.contains(&num),
+            "counting bloom filter still contains item after removal"
+        )
+    }
+
+    #[test]
+    fn counting_bloom_filter_remove_shared_slot_survives() {
+        // Insert many distinct items so that, with overwhelming probability,
+        // some of them share hash positions with item `0`. Every other item
+        // should still be found after `0` is removed once.
+        let mut cbf: CountingBloomFilter<usize> = CountingBloomFilter::new();
+
+        for i in 0..BLOOM_FILTER_MAX {
+            cbf.insert(&i);
+        }
+        cbf.remove(&0);
+
+        assert!(
+            (1..BLOOM_FILTER_MAX).all(|i| cbf.contains(&i)),
+            "removing one item corrupted counters shared with other items"
+        )
+    }
+
+    #[test]
+    fn counting_bloom_filter_saturation_is_pinned() {
+        let num = 1234;
+
+        let mut cbf: CountingBloomFilter<i32, u8> = CountingBloomFilter::new();
+        for _ in 0..=u8::MAX as u16 + 10 {
+            cbf.insert(&num);
+        }
+        cbf.remove(&num);
+
+        assert!(
+            cbf.contains(&num),
+            "a single remove() undid a saturated counter"
+        )
+    }
+
+    #[test]
+    fn sized_bloom_filter_accepts_one() {
+        let num = 1234;
+
+        let mut bf: SizedBloomFilter<i32> =
+            SizedBloomFilter::with_params(BLOOM_FILTER_MAX, 0.01).expect("0.01 is a valid fp_rate");
+        bf.insert(&num);
+
+        assert!(
+            bf.contains(&num),
+            "sized bloom filter doesn't contain inserted item"
+        )
+    }
+
+    #[test]
+    fn sized_bloom_filter_accepts_many() {
+        let mut bf: SizedBloomFilter<usize> =
+            SizedBloomFilter::with_params(BLOOM_FILTER_MAX, 0.01).expect("0.01 is a valid fp_rate");
+
+        (0..BLOOM_FILTER_MAX).for_each(|i| bf.insert(&i));
+
+        assert!(
+            (0..BLOOM_FILTER_MAX).all(|i| bf.contains(&i)),
+            "sized bloom filter doesn't contain inserted items"
+        )
+    }
+
+    #[test]
+    fn sized_bloom_filter_false_positives() {
+        let mut false_pos = 0;
+
+        let mut bf: SizedBloomFilter<usize> =
+            SizedBloomFilter::with_params(BLOOM_FILTER_MAX, FALSE_POS_UPPER_BOUND)
+                .expect("FALSE_POS_UPPER_BOUND is a valid fp_rate");
+        for i in 0..BLOOM_FILTER_MAX {
+            bf.insert(&i);
+        }
+
+        for i in BLOOM_FILTER_MAX..BLOOM_FILTER_MAX * 2 {
+            false_pos += bf.contains(&i) as usize;
+        }
+
+        let rate = false_pos as f64 / BLOOM_FILTER_MAX as f64;
+        assert!(
+            rate < FALSE_POS_UPPER_BOUND * 2.0,
+            "false positive rate {} much higher than target {}",
+            rate,
+            FALSE_POS_UPPER_BOUND
+        )
+    }
+
+    #[test]
+    fn sized_bloom_filter_derived_params_are_sane() {
+        let bf: SizedBloomFilter<usize> =
+            SizedBloomFilter::with_params(BLOOM_FILTER_MAX, 0.01).expect("0.01 is a valid fp_rate");
+
+        assert!(bf.num_bits() > 0, "derived num_bits should be positive");
+        assert!(
+            bf.num_hashes() >= 1,
+            "derived num_hashes should be clamped to at least 1"
+        );
+    }
+
+    #[test]
+    fn sized_bloom_filter_rejects_out_of_range_fp_rate() {
+        assert_eq!(
+            SizedBloomFilter::<usize>::with_params(1000, 0.0).err(),
+            Some(InvalidFpRate),
+            "fp_rate of 0.0 would blow num_bits up towards usize::MAX and abort on allocation"
+        );
+        assert_eq!(
+            SizedBloomFilter::<usize>::with_params(1000, 1.0).err(),
+            Some(InvalidFpRate),
+            "fp_rate of 1.0 would collapse to a 1-bit always-true filter"
+        );
+        assert_eq!(
+            SizedBloomFilter::<usize>::with_params(1000, 1.5).err(),
+            Some(InvalidFpRate)
+        );
+        assert_eq!(
+            SizedBloomFilter::<usize>::with_params(1000, -0.01).err(),
+            Some(InvalidFpRate)
+        );
+    }
+
+    #[test]
+    fn bloom_filter_bytes_round_trip() {
+        let mut bf: BloomFilter<usize> = BloomFilter::new();
+        (0..BLOOM_FILTER_MAX).for_each(|i| bf.insert(&i));
+
+        let restored = BloomFilter::<usize>::from_bytes(&bf.to_bytes())
+            .expect("round-tripped bytes should deserialize");
+
+        assert!(
+            (0..BLOOM_FILTER_MAX).all(|i| restored.contains(&i)),
+            "filter restored from bytes doesn't contain all originally inserted items"
+        )
+    }
+
+    #[test]
+    fn bloom_filter_from_bytes_rejects_mismatched_params() {
+        let bf: BloomFilter<usize, 1000, 3> = BloomFilter::new();
+
+        assert!(
+            BloomFilter::<usize, 2000, 3>::from_bytes(&bf.to_bytes()).is_none(),
+            "from_bytes should reject bytes produced with a different F"
+        )
+    }
+
+    #[test]
+    fn bloom_filter_serde_round_trip() {
+        let mut bf: BloomFilter<usize> = BloomFilter::new();
+        (0..BLOOM_FILTER_MAX).for_each(|i| bf.insert(&i));
+
+        let json = serde_json::to_string(&bf).expect("serialization should succeed");
+        let restored: BloomFilter<usize> =
+            serde_json::from_str(&json).expect("deserialization should succeed");
+
+        assert!(
+            (0..BLOOM_FILTER_MAX).all(|i| restored.contains(&i)),
+            "filter restored from serde JSON doesn't contain all originally inserted items"
+        )
+    }
+
+    #[test]
+    fn bloom_filter_union() {
+        let mut a: BloomFilter<usize> = BloomFilter::new();
+        (0..BLOOM_FILTER_MAX / 2).for_each(|i| a.insert(&i));
+
+        let mut b = a.clone();
+        (BLOOM_FILTER_MAX / 2..BLOOM_FILTER_MAX).for_each(|i| b.insert(&i));
+
+        let union = a.union(&b).expect("cloned filters share hashing seeds");
+
+        assert!(
+            (0..BLOOM_FILTER_MAX).all(|i| union.contains(&i)),
+            "union doesn't contain items inserted into either filter"
+        )
+    }
+
+    #[test]
+    fn bloom_filter_intersect() {
+        let mut a: BloomFilter<usize> = BloomFilter::new();
+        (0..BLOOM_FILTER_MAX).for_each(|i| a.insert(&i));
+
+        // b shares a's hashing seeds (via clone), and a then gets one extra
+        // item, so b's items are a subset of a's and the intersection should
+        // still contain everything b inserted.
+        let b = a.clone();
+        a.insert(&usize::MAX);
+
+        let intersection = a.intersect(&b).expect("cloned filters share hashing seeds");
+
+        assert!(
+            (0..BLOOM_FILTER_MAX).all(|i| intersection.contains(&i)),
+            "intersection doesn't contain items common to both filters"
+        )
+    }
+
+    #[test]
+    fn bloom_filter_combine_rejects_mismatched_seeds() {
+        let a: BloomFilter<usize> = BloomFilter::new();
+        let b: BloomFilter<usize> = BloomFilter::new();
+
+        assert!(
+            a.union(&b).is_err(),
+            "union should reject filters with different hashing seeds"
+        );
+        assert!(
+            a.intersect(&b).is_err(),
+            "intersect should reject filters with different hashing seeds"
+        );
+    }
+
+    #[test]
+    fn bloom_filter_count_estimate() {
+        let mut bf: BloomFilter<usize> = BloomFilter::new();
+        for i in 0..BLOOM_FILTER_MAX {
+            bf.insert(&i);
+        }
+
+        let estimate = bf.count_estimate();
+        let error = (estimate - BLOOM_FILTER_MAX as f64).abs() / BLOOM_FILTER_MAX as f64;
+
+        assert!(
+            error < 0.1,
+            "count_estimate {} too far from actual count {}",
+            estimate,
+            BLOOM_FILTER_MAX
+        )
+    }
 }